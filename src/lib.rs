@@ -300,6 +300,435 @@ pub type Len<List> = <List as TList>::Len;
 /// use the [Empty] or [NonEmpty] constraining traits.
 pub type IsEmpty<List> = <List as TList>::IsEmpty;
 
+use core::ops::Sub;
+use typenum::{Sub1, UInt, Unsigned, UTerm};
+
+#[doc(hidden)]
+pub trait GetAtImpl<N: Unsigned> {
+    type Output;
+}
+
+// at 0 (h : _) = h
+impl<H, T: TList> GetAtImpl<UTerm> for TCons<H, T> {
+    type Output = H;
+}
+
+// at n (_ : t) = at (n - 1) t
+impl<H, T, U: Unsigned, B: Bit> GetAtImpl<UInt<U, B>> for TCons<H, T>
+where
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Unsigned,
+    T: TList + GetAtImpl<Sub1<UInt<U, B>>>,
+{
+    type Output = <T as GetAtImpl<Sub1<UInt<U, B>>>>::Output;
+}
+
+/// Type-level 'function' to return the `N`-th element of a TList (zero-indexed).
+///
+/// Only implemented when `N` is within bounds; an out-of-range `N` simply fails to resolve.
+///
+/// ```rust
+/// use tlist::*;
+/// use typenum::consts::{U0, U1, U2, U3};
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// assert_type_eq!(At<TList![U1, U2, U3], U0>, U1);
+/// assert_type_eq!(At<TList![U1, U2, U3], U2>, U3);
+/// ```
+pub type At<List, N> = <List as GetAtImpl<N>>::Output;
+
+#[doc(hidden)]
+pub trait UpdateAtImpl<N: Unsigned, X> {
+    type Output: TList;
+}
+
+// update 0 x (_ : t) = x : t
+impl<H, T: TList, X> UpdateAtImpl<UTerm, X> for TCons<H, T> {
+    type Output = TCons<X, T>;
+}
+
+// update n x (h : t) = h : update (n - 1) x t
+impl<H, T, U: Unsigned, B: Bit, X> UpdateAtImpl<UInt<U, B>, X> for TCons<H, T>
+where
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Unsigned,
+    T: TList + UpdateAtImpl<Sub1<UInt<U, B>>, X>,
+{
+    type Output = TCons<H, <T as UpdateAtImpl<Sub1<UInt<U, B>>, X>>::Output>;
+}
+
+/// Type-level 'function' to replace the `N`-th element of a TList (zero-indexed) with `X`.
+///
+/// Only implemented when `N` is within bounds; an out-of-range `N` simply fails to resolve.
+///
+/// ```rust
+/// use tlist::*;
+/// use typenum::consts::{U0, U1, U2, U3};
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// assert_type_eq!(Update<TList![U1, U2, U3], U1, u8>, TList![U1, u8, U3]);
+/// ```
+pub type Update<List, N, X> = <List as UpdateAtImpl<N, X>>::Output;
+
+#[doc(hidden)]
+pub trait InsertAtImpl<N: Unsigned, X> {
+    type Output: TList;
+}
+
+// insertAt 0 x t = x : t
+impl<X> InsertAtImpl<UTerm, X> for TNil {
+    type Output = TCons<X, TNil>;
+}
+
+impl<H, T: TList, X> InsertAtImpl<UTerm, X> for TCons<H, T> {
+    type Output = TCons<X, TCons<H, T>>;
+}
+
+// insertAt n x (h : t) = h : insertAt (n - 1) x t
+impl<H, T, U: Unsigned, B: Bit, X> InsertAtImpl<UInt<U, B>, X> for TCons<H, T>
+where
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Unsigned,
+    T: TList + InsertAtImpl<Sub1<UInt<U, B>>, X>,
+{
+    type Output = TCons<H, <T as InsertAtImpl<Sub1<UInt<U, B>>, X>>::Output>;
+}
+
+/// Type-level 'function' to insert `X` into a TList at position `N` (zero-indexed),
+/// shifting the element that used to be at `N`, and all following it, one position to the right.
+///
+/// Only implemented when `N` is within bounds (inserting at `N == Len<List>`, i.e. at the end, is allowed);
+/// an out-of-range `N` simply fails to resolve.
+///
+/// ```rust
+/// use tlist::*;
+/// use typenum::consts::{U0, U1, U2, U3};
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// assert_type_eq!(InsertAt<TList![U2, U3], U0, U1>, TList![U1, U2, U3]);
+/// assert_type_eq!(InsertAt<TList![U1, U3], U1, U2>, TList![U1, U2, U3]);
+/// assert_type_eq!(InsertAt<TList![U1, U2], U2, U3>, TList![U1, U2, U3]);
+/// ```
+pub type InsertAt<List, N, X> = <List as InsertAtImpl<N, X>>::Output;
+
+#[doc(hidden)]
+pub trait RemoveAtImpl<N: Unsigned> {
+    type Output: TList;
+}
+
+// removeAt 0 (_ : t) = t
+impl<H, T: TList> RemoveAtImpl<UTerm> for TCons<H, T> {
+    type Output = T;
+}
+
+// removeAt n (h : t) = h : removeAt (n - 1) t
+impl<H, T, U: Unsigned, B: Bit> RemoveAtImpl<UInt<U, B>> for TCons<H, T>
+where
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Unsigned,
+    T: TList + RemoveAtImpl<Sub1<UInt<U, B>>>,
+{
+    type Output = TCons<H, <T as RemoveAtImpl<Sub1<UInt<U, B>>>>::Output>;
+}
+
+/// Type-level 'function' to remove the `N`-th element of a TList (zero-indexed).
+///
+/// Only implemented when `N` is within bounds; an out-of-range `N` simply fails to resolve.
+///
+/// ```rust
+/// use tlist::*;
+/// use typenum::consts::{U0, U1, U2, U3};
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// assert_type_eq!(RemoveAt<TList![U1, U2, U3], U1>, TList![U1, U3]);
+/// assert_type_eq!(RemoveAt<TList![U1, U2, U3], U0>, TList![U2, U3]);
+/// ```
+pub type RemoveAt<List, N> = <List as RemoveAtImpl<N>>::Output;
+
+#[doc(hidden)]
+pub trait ZipImpl<Rhs: TList> {
+    type Output: TList;
+}
+
+// zip [] _ = []
+impl<Rhs: TList> ZipImpl<Rhs> for TNil {
+    type Output = TNil;
+}
+
+// zip (_:_) [] = []
+impl<H, T: TList> ZipImpl<TNil> for TCons<H, T> {
+    type Output = TNil;
+}
+
+// zip (h:t) (h2:t2) = (h, h2) : zip t t2
+impl<H, T: TList, H2, T2: TList> ZipImpl<TCons<H2, T2>> for TCons<H, T>
+where
+    T: ZipImpl<T2>,
+{
+    type Output = TCons<(H, H2), <T as ZipImpl<T2>>::Output>;
+}
+
+/// Type-level 'function' to zip two TLists together into a TList of 2-tuples.
+///
+/// If the two lists have different lengths, the result is truncated to the shorter one,
+/// just like [`Iterator::zip`].
+///
+/// ```rust
+/// use tlist::*;
+/// use typenum::consts::{U1, U2, U3};
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// assert_type_eq!(Zip<TList![U1, U2], TList![u8, u16]>, TList![(U1, u8), (U2, u16)]);
+///
+/// // Truncates to the shorter list:
+/// assert_type_eq!(Zip<TList![U1, U2, U3], TList![u8]>, TList![(U1, u8)]);
+/// assert_type_eq!(Zip<TList![], TList![u8, u16]>, TList![]);
+/// ```
+pub type Zip<Lhs, Rhs> = <Lhs as ZipImpl<Rhs>>::Output;
+
+#[doc(hidden)]
+pub trait UnzipImpl {
+    type Lhs: TList;
+    type Rhs: TList;
+}
+
+// unzip [] = ([], [])
+impl UnzipImpl for TNil {
+    type Lhs = TNil;
+    type Rhs = TNil;
+}
+
+// unzip ((a, b) : t) = (a : lhs, b : rhs) where (lhs, rhs) = unzip t
+impl<A, B, T: TList + UnzipImpl> UnzipImpl for TCons<(A, B), T> {
+    type Lhs = TCons<A, T::Lhs>;
+    type Rhs = TCons<B, T::Rhs>;
+}
+
+/// Type-level 'function' to split a TList of 2-tuples into a pair of two TLists.
+///
+/// The inverse of [`Zip`].
+///
+/// ```rust
+/// use tlist::*;
+/// use typenum::consts::{U1, U2};
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// assert_type_eq!(
+///     Unzip<TList![(U1, u8), (U2, u16)]>,
+///     (TList![U1, U2], TList![u8, u16])
+/// );
+/// ```
+pub type Unzip<List> = (<List as UnzipImpl>::Lhs, <List as UnzipImpl>::Rhs);
+
+/// A type-level function from one type to another.
+///
+/// Implement this trait on a marker type to use it with [Map].
+///
+/// ```rust
+/// use tlist::*;
+///
+/// struct BoxWrap;
+/// impl TypeFn for BoxWrap {
+///     type Apply<In> = Box<In>;
+/// }
+///
+/// static_assertions::assert_type_eq_all!(<BoxWrap as TypeFn>::Apply<u8>, Box<u8>);
+/// ```
+pub trait TypeFn {
+    /// The result of applying this type-level function to `In`.
+    type Apply<In>;
+}
+
+/// Wraps its input in a [`Box`]. An example [TypeFn] usable with [Map].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BoxWrap;
+impl TypeFn for BoxWrap {
+    type Apply<In> = Box<In>;
+}
+
+#[doc(hidden)]
+pub trait MapImpl<F: TypeFn> {
+    type Output: TList;
+}
+
+// map f [] = []
+impl<F: TypeFn> MapImpl<F> for TNil {
+    type Output = TNil;
+}
+
+// map f (h : t) = f h : map f t
+impl<F: TypeFn, H, T: TList> MapImpl<F> for TCons<H, T>
+where
+    T: MapImpl<F>,
+{
+    type Output = TCons<F::Apply<H>, <T as MapImpl<F>>::Output>;
+}
+
+/// Type-level 'function' to apply the [TypeFn] `F` to every element of a TList.
+///
+/// ```rust
+/// use tlist::*;
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// assert_type_eq!(Map<BoxWrap, TList![u8, u16]>, TList![Box<u8>, Box<u16>]);
+/// assert_type_eq!(Map<BoxWrap, TList![]>, TList![]);
+/// ```
+pub type Map<F, List> = <List as MapImpl<F>>::Output;
+
+/// A type-level function of two arguments, used to fold a TList with [Fold].
+pub trait TypeFn2 {
+    /// The result of applying this type-level function to the accumulator `Acc` and `X`.
+    type Apply<Acc, X>;
+}
+
+#[doc(hidden)]
+pub trait FoldImpl<F: TypeFn2, Acc> {
+    type Output;
+}
+
+// fold f acc [] = acc
+impl<F: TypeFn2, Acc> FoldImpl<F, Acc> for TNil {
+    type Output = Acc;
+}
+
+// fold f acc (h : t) = fold f (f acc h) t
+impl<F: TypeFn2, Acc, H, T: TList> FoldImpl<F, Acc> for TCons<H, T>
+where
+    T: FoldImpl<F, F::Apply<Acc, H>>,
+{
+    type Output = <T as FoldImpl<F, F::Apply<Acc, H>>>::Output;
+}
+
+/// Type-level 'function' to fold a TList left-to-right using the [TypeFn2] `F`, starting from `Acc`.
+///
+/// ```rust
+/// use tlist::*;
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// struct Pair;
+/// impl TypeFn2 for Pair {
+///     type Apply<Acc, X> = (Acc, X);
+/// }
+///
+/// assert_type_eq!(Fold<Pair, (), TList![u8, u16]>, (((), u8), u16));
+/// ```
+pub type Fold<F, Acc, List> = <List as FoldImpl<F, Acc>>::Output;
+
+/// Marker type indicating that a sought-after element was found at the head of the TList.
+///
+/// Used together with [There] to encode *where* an element was found, for [Contains], [IndexOf] and [Remove].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Here;
+
+/// Marker type indicating that a sought-after element was found `Index` positions into the tail of the TList.
+///
+/// Used together with [Here] to encode *where* an element was found, for [Contains], [IndexOf] and [Remove].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct There<Index>(PhantomData<Index>);
+
+/// Constraint which only holds if the concrete type `T` appears somewhere in the TList, at position `Index`.
+///
+/// `Index` is normally left for the compiler to infer (as one of your own function's generic parameters);
+/// this mirrors how [`frunk`](https://docs.rs/frunk)'s `Selector` trait is used, and is required because
+/// Rust cannot otherwise decide, for an arbitrary `H`, whether `H` and `T` are the same type.
+///
+/// ```rust
+/// use tlist::*;
+///
+/// static_assertions::assert_impl_all!(TList![u8, u16, u32]: Contains<u16, There<Here>>);
+/// static_assertions::assert_not_impl_any!(TList![u8, u16, u32]: Contains<u64, There<Here>>);
+/// ```
+///
+/// In practice you rarely spell `Index` out: leave it as a generic parameter of your own
+/// function (or `_` at the call site) and let the compiler infer it, the same way you would
+/// use [`frunk::Selector`](https://docs.rs/frunk/latest/frunk/indices/trait.Selector.html):
+///
+/// ```rust
+/// use tlist::*;
+///
+/// fn assert_contains<List, T, Index>()
+/// where
+///     List: Contains<T, Index>,
+/// {
+/// }
+///
+/// assert_contains::<TList![u8, u16, u32], u16, _>();
+/// ```
+pub trait Contains<T, Index> {}
+
+// contains t (t : _) = true
+impl<T, Rest: TList> Contains<T, Here> for TCons<T, Rest> {}
+
+// contains t (_ : rest) = contains t rest
+impl<H, T, Rest: TList, Index> Contains<T, There<Index>> for TCons<H, Rest> where Rest: Contains<T, Index> {}
+
+#[doc(hidden)]
+pub trait IndexOfImpl<T, Index> {
+    type Output: UnsignedExt;
+}
+
+// indexOf t (t : _) = 0
+impl<T, Rest: TList> IndexOfImpl<T, Here> for TCons<T, Rest> {
+    type Output = U0;
+}
+
+// indexOf t (_ : rest) = 1 + indexOf t rest
+impl<H, T, Rest: TList, Index> IndexOfImpl<T, There<Index>> for TCons<H, Rest>
+where
+    Rest: IndexOfImpl<T, Index>,
+{
+    type Output = <<Rest as IndexOfImpl<T, Index>>::Output as UnsignedExt>::Succ;
+}
+
+/// Type-level 'function' to find the position of `T` in a TList, at the occurrence selected by `Index`.
+///
+/// See [Contains] for why `Index` is needed and how it is normally inferred.
+/// When `T` occurs more than once, `Index` cannot be inferred and selects which occurrence is used;
+/// it is not restricted to the first one.
+///
+/// ```rust
+/// use tlist::*;
+/// use typenum::consts::{U0, U1, U2};
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// assert_type_eq!(U0, IndexOf<TList![u8, u16, u32], u8, Here>);
+/// assert_type_eq!(U2, IndexOf<TList![u8, u16, u32], u32, There<There<Here>>>);
+/// ```
+pub type IndexOf<List, T, Index> = <List as IndexOfImpl<T, Index>>::Output;
+
+#[doc(hidden)]
+pub trait RemoveImpl<T, Index> {
+    type Output: TList;
+}
+
+// remove t (t : rest) = rest
+impl<T, Rest: TList> RemoveImpl<T, Here> for TCons<T, Rest> {
+    type Output = Rest;
+}
+
+// remove t (h : rest) = h : remove t rest
+impl<H, T, Rest: TList, Index> RemoveImpl<T, There<Index>> for TCons<H, Rest>
+where
+    Rest: RemoveImpl<T, Index>,
+{
+    type Output = TCons<H, <Rest as RemoveImpl<T, Index>>::Output>;
+}
+
+/// Type-level 'function' to remove one occurrence of `T`, the one selected by `Index`, from a TList.
+///
+/// See [Contains] for why `Index` is needed and how it is normally inferred.
+/// When `T` occurs more than once, `Index` cannot be inferred and selects which occurrence is removed;
+/// it is not restricted to the first one.
+///
+/// ```rust
+/// use tlist::*;
+/// use static_assertions::assert_type_eq_all as assert_type_eq;
+///
+/// assert_type_eq!(TList![u16, u32], Remove<TList![u8, u16, u32], u8, Here>);
+/// assert_type_eq!(TList![u8, u32], Remove<TList![u8, u16, u32], u16, There<Here>>);
+/// ```
+pub type Remove<List, T, Index> = <List as RemoveImpl<T, Index>>::Output;
+
 /// Constraint which only holds if a TList is a prefix of `Other`.
 ///
 /// This is not a type-level 'function', but rather a constraint you can use to make compiler errors more readable.
@@ -350,6 +779,67 @@ impl<F, FS: TList> EitherPrefix<TNil> for TCons<F, FS> {}
 // eitherPrefix (f : fs) (g : gs) == true
 impl<F, FS: TList, GS: TList> EitherPrefix<TCons<F, GS>> for TCons<F, FS> where FS: EitherPrefix<GS> {}
 
+/// A visitor used by [reify] to fold a [trait@TList] into a runtime value, once per element type.
+///
+/// Implement this on your own marker type to materialize compile-time type information
+/// (e.g. [`core::any::type_name`], [`core::any::TypeId`] or [`core::mem::size_of`] of each element)
+/// into a runtime value.
+pub trait ReifyFold<Acc> {
+    /// Folds one more element type `H` into the accumulator.
+    fn step<H>(acc: Acc) -> Acc;
+}
+
+#[doc(hidden)]
+pub trait ReifyImpl: TList {
+    fn reify_impl<F: ReifyFold<Acc>, Acc>(acc: Acc) -> Acc;
+}
+
+// reify f acc [] = acc
+impl ReifyImpl for TNil {
+    fn reify_impl<F: ReifyFold<Acc>, Acc>(acc: Acc) -> Acc {
+        acc
+    }
+}
+
+// reify f acc (h : t) = reify f (f::step::<h>(acc)) t
+impl<H, T: TList + ReifyImpl> ReifyImpl for TCons<H, T> {
+    fn reify_impl<F: ReifyFold<Acc>, Acc>(acc: Acc) -> Acc {
+        T::reify_impl::<F, Acc>(F::step::<H>(acc))
+    }
+}
+
+/// Walks a [trait@TList] at monomorphization time, invoking the visitor `F` once per element type
+/// to fold a runtime accumulator, starting from `init`.
+///
+/// This is the bridge from a compile-time TList to a runtime value: the canonical use case is
+/// collecting `core::any::type_name::<H>()` or `core::any::TypeId::of::<H>()` of every element
+/// into a `Vec`.
+///
+/// ```rust
+/// use tlist::*;
+///
+/// struct CollectTypeNames;
+/// impl ReifyFold<Vec<&'static str>> for CollectTypeNames {
+///     fn step<H>(mut acc: Vec<&'static str>) -> Vec<&'static str> {
+///         acc.push(core::any::type_name::<H>());
+///         acc
+///     }
+/// }
+///
+/// let names = reify::<CollectTypeNames, TList![u8, u16, u32], _>(Vec::new());
+/// assert_eq!(
+///     names,
+///     vec![
+///         core::any::type_name::<u8>(),
+///         core::any::type_name::<u16>(),
+///         core::any::type_name::<u32>(),
+///     ]
+/// );
+/// ```
+pub fn reify<F: ReifyFold<Acc>, List: ReifyImpl, Acc>(init: Acc) -> Acc {
+    List::reify_impl::<F, Acc>(init)
+}
+
 #[cfg(test)]
 pub mod tests {
     // Since all of this is type-level code,
@@ -406,4 +896,125 @@ pub mod tests {
         assert_type_eq!(B1, IsEmpty<TList![]>);
         assert_type_eq!(B0, IsEmpty<TList![i32]>);
     }
+
+    #[test]
+    fn at() {
+        assert_type_eq!(U1, At<TList![U1, U2, U3], U0>);
+        assert_type_eq!(U2, At<TList![U1, U2, U3], U1>);
+        assert_type_eq!(U3, At<TList![U1, U2, U3], U2>);
+    }
+
+    #[test]
+    fn update() {
+        assert_type_eq!(TList![U42, U2, U3], Update<TList![U1, U2, U3], U0, U42>);
+        assert_type_eq!(TList![U1, U42, U3], Update<TList![U1, U2, U3], U1, U42>);
+    }
+
+    #[test]
+    fn insert_at() {
+        assert_type_eq!(TList![U42, U1, U2], InsertAt<TList![U1, U2], U0, U42>);
+        assert_type_eq!(TList![U1, U42, U2], InsertAt<TList![U1, U2], U1, U42>);
+        assert_type_eq!(TList![U1, U2, U42], InsertAt<TList![U1, U2], U2, U42>);
+        assert_type_eq!(TList![U42], InsertAt<TList![], U0, U42>);
+    }
+
+    #[test]
+    fn remove_at() {
+        assert_type_eq!(TList![U2, U3], RemoveAt<TList![U1, U2, U3], U0>);
+        assert_type_eq!(TList![U1, U3], RemoveAt<TList![U1, U2, U3], U1>);
+        assert_type_eq!(TList![U1, U2], RemoveAt<TList![U1, U2, U3], U2>);
+    }
+
+    #[test]
+    fn zip() {
+        assert_type_eq!(TList![], Zip<TList![], TList![]>);
+        assert_type_eq!(
+            TList![(U1, U2), (U3, U4)],
+            Zip<TList![U1, U3], TList![U2, U4]>
+        );
+        assert_type_eq!(TList![(U1, U2)], Zip<TList![U1, U3], TList![U2]>);
+        assert_type_eq!(TList![], Zip<TList![U1, U3], TList![]>);
+    }
+
+    #[test]
+    fn unzip() {
+        assert_type_eq!((TList![], TList![]), Unzip<TList![]>);
+        assert_type_eq!(
+            (TList![U1, U3], TList![U2, U4]),
+            Unzip<TList![(U1, U2), (U3, U4)]>
+        );
+    }
+
+    #[test]
+    fn map() {
+        assert_type_eq!(TList![], Map<BoxWrap, TList![]>);
+        assert_type_eq!(TList![Box<U1>, Box<U2>], Map<BoxWrap, TList![U1, U2]>);
+    }
+
+    #[test]
+    fn fold() {
+        struct Pair;
+        impl TypeFn2 for Pair {
+            type Apply<Acc, X> = (Acc, X);
+        }
+
+        assert_type_eq!((), Fold<Pair, (), TList![]>);
+        assert_type_eq!(
+            (((), U1), U2),
+            Fold<Pair, (), TList![U1, U2]>
+        );
+    }
+
+    #[test]
+    fn contains() {
+        static_assertions::assert_impl_all!(TList![u8, u16, u32]: Contains<u8, Here>);
+        static_assertions::assert_impl_all!(TList![u8, u16, u32]: Contains<u32, There<There<Here>>>);
+        static_assertions::assert_not_impl_any!(TList![u8, u16, u32]: Contains<u64, Here>);
+
+        // Index is normally left for the compiler to infer, not spelled out by hand.
+        fn assert_contains<List, T, Index>()
+        where
+            List: Contains<T, Index>,
+        {
+        }
+        assert_contains::<TList![u8, u16, u32], u16, _>();
+    }
+
+    #[test]
+    fn index_of() {
+        assert_type_eq!(U0, IndexOf<TList![u8, u16, u32], u8, Here>);
+        assert_type_eq!(U1, IndexOf<TList![u8, u16, u32], u16, There<Here>>);
+        assert_type_eq!(U2, IndexOf<TList![u8, u16, u32], u32, There<There<Here>>>);
+    }
+
+    #[test]
+    fn remove() {
+        assert_type_eq!(TList![u16, u32], Remove<TList![u8, u16, u32], u8, Here>);
+        assert_type_eq!(TList![u8, u32], Remove<TList![u8, u16, u32], u16, There<Here>>);
+        assert_type_eq!(TList![u8, u16], Remove<TList![u8, u16, u32], u32, There<There<Here>>>);
+    }
+
+    #[test]
+    fn reify_type_names() {
+        struct CollectTypeNames;
+        impl ReifyFold<Vec<&'static str>> for CollectTypeNames {
+            fn step<H>(mut acc: Vec<&'static str>) -> Vec<&'static str> {
+                acc.push(core::any::type_name::<H>());
+                acc
+            }
+        }
+
+        let names = reify::<CollectTypeNames, TList![u8, u16, u32], _>(Vec::new());
+        assert_eq!(
+            names,
+            vec![
+                core::any::type_name::<u8>(),
+                core::any::type_name::<u16>(),
+                core::any::type_name::<u32>(),
+            ]
+        );
+
+        let empty = reify::<CollectTypeNames, TList![], _>(Vec::new());
+        assert!(empty.is_empty());
+    }
 }